@@ -1,4 +1,5 @@
-use clap::{arg, value_parser, ArgAction, Command};
+use clap::{arg, value_parser, ArgAction, Command, ValueEnum};
+use std::io::Write;
 
 fn cli() -> Command {
     Command::new("olympus")
@@ -25,6 +26,56 @@ fn cli() -> Command {
                 .default_value("0.01"),
         )
         .arg(arg!(-t --tartarus "calculate distance to tartarus").action(ArgAction::SetTrue))
+        .arg(
+            arg!(--atm <MODEL> "atmosphere density model")
+                .value_parser(value_parser!(AtmModelKind))
+                .default_value("layered"),
+        )
+        .arg(
+            arg!(--"atm-rho0" [RHO0] "reference density (kg/m^3) for constant/exponential atm models")
+                .value_parser(value_parser!(f64))
+                .default_value("1.225"),
+        )
+        .arg(
+            arg!(--"atm-ref-alt" [ALT] "reference altitude (m) for the exponential atm model")
+                .value_parser(value_parser!(f64))
+                .default_value("0"),
+        )
+        .arg(
+            arg!(--"atm-scale-height" [H] "scale height (m) for the exponential atm model")
+                .value_parser(value_parser!(f64))
+                .default_value("8500"),
+        )
+        .arg(
+            arg!(-s --solver <SOLVER> "integration method")
+                .value_parser(value_parser!(Solver))
+                .default_value("euler"),
+        )
+        .arg(
+            arg!(--shape <SHAPE> "object shape, for the drag coefficient model")
+                .value_parser(value_parser!(Shape))
+                .default_value("cube"),
+        )
+        .arg(
+            // no default: Coriolis/centrifugal tracking is opt-in since it's
+            // only meaningful once a release latitude is known
+            arg!(--latitude [DEGREES] "latitude of the drop (degrees), enables Coriolis/centrifugal tracking")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            arg!(--csv [PATH] "write per-sample trajectory data to this CSV file")
+                .value_parser(value_parser!(std::path::PathBuf)),
+        )
+        .arg(
+            arg!(--"sample-stride" [N] "write every Nth integration sample to --csv")
+                .value_parser(value_parser!(u64))
+                .default_value("1"),
+        )
+        .arg(
+            arg!(--units <UNITS> "unit system for human-readable distance/speed output")
+                .value_parser(value_parser!(Units))
+                .default_value("astro"),
+        )
 }
 
 //gravitational constant  m^3/(kg s^2)
@@ -33,6 +84,350 @@ const M_EARTH: f64 = 5.97e24;
 // average radius
 const R_EARTH: f64 = 6.367e6;
 const KARMAN_LINE: f64 = 100000.0;
+// Earth's sidereal rotation rate, rad/s
+const OMEGA_EARTH: f64 = 7.292e-5;
+// standard sea-level speed of sound, m/s (the atmosphere model has no
+// temperature profile yet, so this is used at every altitude for now)
+const SPEED_OF_SOUND_SEA_LEVEL: f64 = 340.29;
+const MOON_DISTANCE_M: f64 = 384_400_000.0;
+const ASTRONOMICAL_UNIT_M: f64 = 1.496e11;
+
+/// Unit system for human-readable output, picked on the CLI via `--units`.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Units {
+    Si,
+    Imperial,
+    Astro,
+}
+
+/// Render `meters` in the largest sensible unit for `units`, `nature`-style:
+/// SI walks mm/m/km, Astro extends that ladder out through Earth radii, Moon
+/// distances and AU, and Imperial walks ft/mi.
+fn format_distance(meters: f64, units: Units) -> String {
+    let abs_m = meters.abs();
+    match units {
+        Units::Imperial => {
+            let miles = meters / 1609.344;
+            if miles.abs() >= 0.1 {
+                format!("{:.3} mi", miles)
+            } else {
+                format!("{:.2} ft", meters * 3.28084)
+            }
+        }
+        Units::Astro => {
+            let au = meters / ASTRONOMICAL_UNIT_M;
+            let moon_distances = meters / MOON_DISTANCE_M;
+            let earth_radii = meters / R_EARTH;
+            if au.abs() >= 0.1 {
+                format!("{:.4} AU", au)
+            } else if moon_distances.abs() >= 0.1 {
+                format!("{:.3} Moon distances", moon_distances)
+            } else if earth_radii.abs() >= 0.5 {
+                format!("{:.3} Earth radii", earth_radii)
+            } else if abs_m >= 1000.0 {
+                format!("{:.2} km", meters / 1000.0)
+            } else if abs_m >= 1.0 {
+                format!("{:.2} m", meters)
+            } else {
+                format!("{:.2} mm", meters * 1000.0)
+            }
+        }
+        Units::Si => {
+            if abs_m >= 1000.0 {
+                format!("{:.3} km", meters / 1000.0)
+            } else if abs_m >= 1.0 {
+                format!("{:.2} m", meters)
+            } else {
+                format!("{:.2} mm", meters * 1000.0)
+            }
+        }
+    }
+}
+
+/// Render `mps` (m/s) in the largest sensible unit: m/s, km/h, or Mach
+/// (using `SPEED_OF_SOUND_SEA_LEVEL`) from Mach 0.5 upward.
+/// Imperial always reports mph, matching `format_distance`'s ft/mi ladder.
+fn format_speed(mps: f64, units: Units) -> String {
+    if let Units::Imperial = units {
+        return format!("{:.2} mph", mps * 2.237);
+    }
+    let mach = mps / SPEED_OF_SOUND_SEA_LEVEL;
+    if mach.abs() >= 0.5 {
+        format!("{:.3} Mach", mach)
+    } else if mps.abs() >= 50.0 {
+        format!("{:.2} km/h", mps * 3.6)
+    } else {
+        format!("{:.2} m/s", mps)
+    }
+}
+
+/// Selectable atmosphere density model, picked on the CLI via `--atm`.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum AtmModelKind {
+    Constant,
+    Exponential,
+    Layered,
+}
+
+/// A concrete, parameterized atmosphere density model.
+///
+/// `Layered` is a piecewise-exponential US-standard-atmosphere fit covering
+/// 0-100km; above its top layer it keeps decaying on that layer's scale
+/// height instead of snapping to zero, which matters for drops that start
+/// well above the Karman line.
+#[derive(Debug, Clone, Copy)]
+enum AtmDensity {
+    Constant(f64),
+    Exponential {
+        rho0: f64,
+        ref_alt_m: f64,
+        scale_height_m: f64,
+    },
+    Layered,
+}
+
+impl AtmDensity {
+    fn from_cli(matches: &clap::ArgMatches) -> AtmDensity {
+        let kind = matches.get_one::<AtmModelKind>("atm").unwrap();
+        let rho0 = *matches.get_one::<f64>("atm-rho0").unwrap();
+        let ref_alt_m = *matches.get_one::<f64>("atm-ref-alt").unwrap();
+        let scale_height_m = *matches.get_one::<f64>("atm-scale-height").unwrap();
+        match kind {
+            AtmModelKind::Constant => AtmDensity::Constant(rho0),
+            AtmModelKind::Exponential => AtmDensity::Exponential {
+                rho0,
+                ref_alt_m,
+                scale_height_m,
+            },
+            AtmModelKind::Layered => AtmDensity::Layered,
+        }
+    }
+
+    /// Density (kg/m^3) at `elevation` meters above sea level. Below sea
+    /// level this ignores the selected model and keeps the original
+    /// barometric (ideal-gas-law) formula, whose density rises with depth,
+    /// so the fictional sub-surface tartarus descent keeps the drag it had
+    /// before the atmosphere model became selectable.
+    fn density(&self, elevation: f64) -> f64 {
+        if elevation <= 0.0 {
+            return subsurface_barometric_density(elevation);
+        }
+        match self {
+            AtmDensity::Constant(rho0) => *rho0,
+            AtmDensity::Exponential {
+                rho0,
+                ref_alt_m,
+                scale_height_m,
+            } => rho0 * (-(elevation - ref_alt_m) / scale_height_m).exp(),
+            AtmDensity::Layered => layered_density(elevation),
+        }
+    }
+}
+
+/// Ideal-gas-law barometric density (kg/m^3) below sea level (`elevation <=
+/// 0`), unchanged from the original pre-pluggable-atmosphere formula: density
+/// rises with depth rather than staying flat, matching the pressure increase
+/// implied by extrapolating the barometric formula downward.
+fn subsurface_barometric_density(elevation: f64) -> f64 {
+    let p_sea_level = 101325.0;
+    let p = p_sea_level * (1.0 - 2.25577e-5 * elevation).powf(5.25588);
+    // ideal gas constant
+    let r = 8.314;
+    let t = 288.0;
+    // molar mass of air kg/mol
+    let m = 0.02897;
+    (p * m) / (r * t)
+}
+
+/// One exponential segment of the layered US-standard-atmosphere fit:
+/// `rho(h) = coefficient_kg_m3 * exp(-h / scale_height_m)` for
+/// `h < upper_bound_m`.
+#[derive(Debug, Clone, Copy)]
+struct AtmLayer {
+    upper_bound_m: f64,
+    coefficient_kg_m3: f64,
+    scale_height_m: f64,
+}
+
+// US-standard-atmosphere layer boundaries and scale heights (Linsley
+// parameterization). The published per-layer coefficients are fit to
+// atmospheric depth, not density, so plugging them in directly makes density
+// jump a few percent at each boundary; `us_standard_layers` instead anchors
+// the first layer at sea level and re-derives every later coefficient so
+// density is actually continuous across boundaries.
+const US_STANDARD_BOUNDARIES: [(f64, f64); 4] = [
+    (4_000.0, 9941.8638),
+    (10_000.0, 8781.5355),
+    (40_000.0, 6361.4304),
+    (100_000.0, 7721.7016),
+];
+
+const SEA_LEVEL_DENSITY_KG_M3: f64 = 1.225;
+
+fn us_standard_layers() -> [AtmLayer; 4] {
+    let mut layers = [AtmLayer {
+        upper_bound_m: 0.0,
+        coefficient_kg_m3: 0.0,
+        scale_height_m: 0.0,
+    }; 4];
+    let mut lower_bound_m = 0.0;
+    let mut coefficient_kg_m3 = SEA_LEVEL_DENSITY_KG_M3;
+    for (i, &(upper_bound_m, scale_height_m)) in US_STANDARD_BOUNDARIES.iter().enumerate() {
+        if i > 0 {
+            let prev = layers[i - 1];
+            let boundary_density =
+                prev.coefficient_kg_m3 * (-lower_bound_m / prev.scale_height_m).exp();
+            coefficient_kg_m3 = boundary_density / (-lower_bound_m / scale_height_m).exp();
+        }
+        layers[i] = AtmLayer {
+            upper_bound_m,
+            coefficient_kg_m3,
+            scale_height_m,
+        };
+        lower_bound_m = upper_bound_m;
+    }
+    layers
+}
+
+fn layered_density(h: f64) -> f64 {
+    let layers = us_standard_layers();
+    let layer = layers
+        .iter()
+        .find(|l| h < l.upper_bound_m)
+        .unwrap_or(&layers[layers.len() - 1]);
+    layer.coefficient_kg_m3 * (-h / layer.scale_height_m).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sea_level_density_matches_standard_atmosphere() {
+        let rho = layered_density(0.0);
+        assert!((rho - 1.225).abs() < 0.01, "got {rho}");
+    }
+
+    #[test]
+    fn continuous_across_layer_boundaries() {
+        for &(boundary, _) in &US_STANDARD_BOUNDARIES {
+            let just_below = layered_density(boundary - 0.01);
+            let at_boundary = layered_density(boundary);
+            let rel_err = (just_below - at_boundary).abs() / just_below;
+            assert!(
+                rel_err < 1e-3,
+                "boundary {boundary}: {just_below} vs {at_boundary}"
+            );
+        }
+    }
+
+    #[test]
+    fn exponential_tail_above_100km_keeps_decaying_instead_of_snapping_to_zero() {
+        let at_100km = layered_density(100_000.0);
+        let above = layered_density(150_000.0);
+        assert!(at_100km > 0.0);
+        assert!(above > 0.0);
+        assert!(above < at_100km);
+    }
+}
+
+/// Integration method, picked on the CLI via `-s/--solver`.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Solver {
+    Euler,
+    Rk4,
+}
+
+/// Per-run parameters threaded through every force/step function.
+///
+/// `latitude_rad` is `None` unless the user passed `--latitude`: Coriolis and
+/// centrifugal tracking is opt-in, since assuming the release point corotates
+/// with the Earth up to a mythological 573,851km is only physically sound
+/// near the surface (well past geostationary radius the "centrifugal" term
+/// exceeds gravity and the object simply never falls).
+struct SimConfig {
+    mass: f64,
+    width: f64,
+    shape: Shape,
+    latitude_rad: Option<f64>,
+}
+
+/// State vector `[distance, velocity, east, v_east]`: radial distance above
+/// sea level and its rate of fall, plus the Coriolis-driven eastward ground
+/// position and its rate.
+type State = [f64; 4];
+
+/// Net radial acceleration (gravity minus drag) at `distance` moving at
+/// `velocity`, as felt by an object of `mass` and `width`.
+fn acceleration(distance: f64, velocity: f64, cfg: &SimConfig, atm: &AtmDensity) -> f64 {
+    let m_earth = mass_of_earth(distance);
+    let density = atm.density(distance);
+    let a_g = f_gravity(cfg.mass, m_earth, distance + R_EARTH) / cfg.mass;
+    let a_d = f_drag(density, velocity, cfg.width, cfg.shape) / cfg.mass;
+    a_g - a_d
+}
+
+/// `dy/dt = f(y)` for `y = [distance, velocity, east, v_east]`, in the
+/// Earth-fixed rotating frame: adds the Coriolis term `-2*OMEGA x v` and the
+/// centrifugal term `-OMEGA x (OMEGA x r)` on top of gravity and drag.
+fn derivative(y: State, cfg: &SimConfig, atm: &AtmDensity) -> State {
+    let [distance, velocity, _east, v_east] = y;
+    let a_rad = acceleration(distance, velocity, cfg, atm);
+    let (coriolis_up, centrifugal_up, coriolis_east) = match cfg.latitude_rad {
+        Some(latitude_rad) => {
+            // Clamp to sea level: below ground this is already the
+            // fictional deep-Earth fall to tartarus, and the rotating-frame
+            // terms don't meaningfully extend down there.
+            let radius = R_EARTH + distance.max(0.0);
+            let cos_lat = latitude_rad.cos();
+            (
+                2.0 * OMEGA_EARTH * v_east * cos_lat,
+                OMEGA_EARTH * OMEGA_EARTH * radius * cos_lat * cos_lat,
+                2.0 * OMEGA_EARTH * velocity * cos_lat,
+            )
+        }
+        None => (0.0, 0.0, 0.0),
+    };
+    [
+        -velocity,
+        a_rad - coriolis_up - centrifugal_up,
+        v_east,
+        coriolis_east,
+    ]
+}
+
+fn scale_add(y: State, dy: State, scale: f64) -> State {
+    [
+        y[0] + dy[0] * scale,
+        y[1] + dy[1] * scale,
+        y[2] + dy[2] * scale,
+        y[3] + dy[3] * scale,
+    ]
+}
+
+fn step_euler(y: State, delta_t: f64, cfg: &SimConfig, atm: &AtmDensity) -> State {
+    let k1 = derivative(y, cfg, atm);
+    scale_add(y, k1, delta_t)
+}
+
+fn step_rk4(y: State, delta_t: f64, cfg: &SimConfig, atm: &AtmDensity) -> State {
+    let k1 = derivative(y, cfg, atm);
+    let k2 = derivative(scale_add(y, k1, delta_t / 2.0), cfg, atm);
+    let k3 = derivative(scale_add(y, k2, delta_t / 2.0), cfg, atm);
+    let k4 = derivative(scale_add(y, k3, delta_t), cfg, atm);
+    let mut out = [0.0; 4];
+    for i in 0..4 {
+        out[i] = y[i] + delta_t / 6.0 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+    }
+    out
+}
+
+fn step(solver: Solver, y: State, delta_t: f64, cfg: &SimConfig, atm: &AtmDensity) -> State {
+    match solver {
+        Solver::Euler => step_euler(y, delta_t, cfg, atm),
+        Solver::Rk4 => step_rk4(y, delta_t, cfg, atm),
+    }
+}
 
 fn main() {
     let matches = cli().get_matches();
@@ -42,70 +437,121 @@ fn main() {
     let mass = *matches.get_one::<f64>("mass").unwrap();
     let delta_t = *matches.get_one::<f64>("integration_time").unwrap();
     let tartarus = matches.get_flag("tartarus");
-    let mut velocity = 0.0;
+    let atm = AtmDensity::from_cli(&matches);
+    let solver = *matches.get_one::<Solver>("solver").unwrap();
+    let shape = *matches.get_one::<Shape>("shape").unwrap();
+    let latitude_rad = matches.get_one::<f64>("latitude").map(|d| d.to_radians());
+    let units = *matches.get_one::<Units>("units").unwrap();
+    let cfg = SimConfig {
+        mass,
+        width,
+        shape,
+        latitude_rad,
+    };
+    let sample_stride = (*matches.get_one::<u64>("sample-stride").unwrap()).max(1);
+    let mut csv = matches.get_one::<std::path::PathBuf>("csv").map(|path| {
+        let file = std::fs::File::create(path).expect("failed to create --csv output file");
+        let mut writer = std::io::BufWriter::new(file);
+        writeln!(
+            writer,
+            "t,distance,altitude,velocity,mach,a_gravity,a_drag,density,mass_enclosed"
+        )
+        .unwrap();
+        writer.flush().unwrap();
+        writer
+    });
+    let mut y: State = [distance, 0.0, 0.0, 0.0];
     let mut t: f64 = 0.0;
-    let table_limit = (DENSITY_AT_10KM.len() - 1) as f64 * 10000.0;
-    println!("distance={}, width={}, mass={}", distance, width, mass);
-    while (tartarus && t < 9.0 * 24.0 * 3600.0) || (distance - (velocity * delta_t) > 0.0) {
-        let m_earth = mass_of_earth(distance);
-        let density = air_density(distance);
-        let a_g = f_gravity(mass, m_earth, distance + R_EARTH) / mass;
-        let a_d = if distance < table_limit {
-            f_drag(density, velocity, width) / mass
-        } else {
-            0.0
-        };
-        velocity += (a_g - a_d) * delta_t;
+    let mut step_idx: u64 = 0;
+    println!(
+        "distance={}, width={}, mass={}",
+        format_distance(distance, units),
+        width,
+        mass
+    );
+    // A corotating release is unbound once centrifugal acceleration at the
+    // release radius meets or exceeds gravity minus drag: the anvil would
+    // drift outward forever rather than fall, so bail out instead of
+    // grinding through the full max_t time cap below at the default
+    // integration step.
+    if let Some(latitude_rad) = latitude_rad {
+        let radius0 = R_EARTH + distance.max(0.0);
+        let centrifugal0 = OMEGA_EARTH * OMEGA_EARTH * radius0 * latitude_rad.cos().powi(2);
+        let a_rad0 = acceleration(distance, 0.0, &cfg, &atm);
+        if centrifugal0 >= a_rad0 {
+            println!(
+                "\nAt latitude {:.2} degrees and {} above the earth, centrifugal acceleration ({:.4} m/s^2) meets or exceeds gravity minus drag ({:.4} m/s^2): the anvil is unbound and will never fall. Aborting instead of simulating.",
+                latitude_rad.to_degrees(),
+                format_distance(distance, units),
+                centrifugal0,
+                a_rad0
+            );
+            return;
+        }
+    }
+    // A corotating release can be unbound at extreme latitude+distance
+    // combinations (well past geostationary radius the object never falls
+    // back), so cap total run time rather than loop forever.
+    let max_t = 100.0 * 9.0 * 24.0 * 3600.0;
+    while ((tartarus && t < 9.0 * 24.0 * 3600.0) || (y[0] - (y[1] * delta_t) > 0.0)) && t < max_t {
+        let m_earth = mass_of_earth(y[0]);
+        let density = atm.density(y[0]);
+        let a_g = f_gravity(mass, m_earth, y[0] + R_EARTH) / mass;
+        let a_d = f_drag(density, y[1], width, shape) / mass;
+        if let Some(writer) = csv.as_mut()
+            && step_idx.is_multiple_of(sample_stride)
+        {
+            // distance and altitude coincide in this purely radial model
+            let mach = y[1].abs() / SPEED_OF_SOUND_SEA_LEVEL;
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{}",
+                t, y[0], y[0], y[1], mach, a_g, a_d, density, m_earth
+            )
+            .unwrap();
+            writer.flush().unwrap();
+        }
         if ((t - t.floor()) < delta_t)
-            && (t<20.0 || (distance > 0.0 && distance < 5.0* KARMAN_LINE) || (t as i64 % (3600 * 6)) == 0)
+            && (t<20.0 || (y[0] > 0.0 && y[0] < 5.0* KARMAN_LINE) || (t as i64 % (3600 * 6)) == 0)
         {
             println!(
-                "{:.2} sec({:.2} days): v:{:.2} m/s ({:.2} mph), d:{:.2} m ({:.2} moonunits) ag:{:.2} ad:{:.2} me:{:.2e} density:{:.2}",
+                "{:.2} sec({:.2} days): v:{}, d:{} ag:{:.2} ad:{:.2} me:{:.2e} density:{:.2}",
                 t,
-                t as f64 / (60.0 * 60.0 * 24.0),
-                velocity,
-                velocity * 2.237,
-                distance,
-                distance / 384400000.0,
+                t / (60.0 * 60.0 * 24.0),
+                format_speed(y[1], units),
+                format_distance(y[0], units),
                 a_g, a_d, m_earth, density
             );
         }
-        distance -= velocity * delta_t;
+        y = step(solver, y, delta_t, &cfg, &atm);
         t += delta_t;
+        step_idx += 1;
     }
+    distance = y[0];
     println!(
-        "\nA {} kg anvil, dropped from {} km above the earth, will strike  after {:.2} days.",
+        "\nA {} kg anvil, dropped from {} above the earth, will strike  after {:.2} days.",
         mass,
-        initial_distance / 1000.0,
-        t as f64 / (60.0 * 60.0 * 24.0)
+        format_distance(initial_distance, units),
+        t / (60.0 * 60.0 * 24.0)
     );
     println!(
-        "Precicely, after {:.2} seconds it was {:.2} m above sea level, moving at {:.2} m/s",
-        t, distance, velocity
+        "Precicely, after {:.2} seconds it was {} above sea level, moving at {}",
+        t,
+        format_distance(distance, units),
+        format_speed(y[1], units)
     );
-}
-
-// at -1000m it is 1.347
-const DENSITY_AT_10KM: [f64; 11] = [
-    1.22, 0.413, 8.89e-2, 1.84e-2, 4e-3, 1.03e-3, 3.1e-4, 8.3e-5, 1.85e-5, 4.12e-6, 0.00,
-];
-fn air_density(elevation: f64) -> f64 {
-    if elevation > KARMAN_LINE {
-	0.0
-    } else if elevation > 0.0 {
-	let index = elevation as usize / 10000;
-	//let p_sea_level = 101.325 / 98.06;
-	//println!("{:.2} {:.2}", p_sea_level * (1.0 - 2.25577e-5 * elevation).powf(5.25588),DENSITY_AT_10KM[index]);
-	DENSITY_AT_10KM[index]
-    } else {
-	let p_sea_level = 101325.0;	// in Pa 
-	let p = p_sea_level * (1.0 - 2.25577e-5 * elevation).powf(5.25588);
-	// ideal gas constant
-	let r = 8.314;
-	let t = 288.0;
-	// molar mass of air kg/mol
-	let m = 0.02897;
-	(p * m) / ( r * t)
+    if latitude_rad.is_some() {
+        let eastward = y[2];
+        let bearing = if eastward >= 0.0 {
+            "090 (east)"
+        } else {
+            "270 (west)"
+        };
+        println!(
+            "Coriolis drift: landed {} off the release meridian, bearing {}",
+            format_distance(eastward.abs(), units),
+            bearing
+        );
     }
 }
 
@@ -127,12 +573,51 @@ fn f_gravity(m1: f64, m2: f64, d: f64) -> f64 {
     (G * m1 * m2) / (d * d)
 }
 
-// presume anvil is a cube
-fn f_drag(density: f64, velocity: f64, width: f64) -> f64 {
-    0.5 * density * velocity * velocity * width * width * 1.09
+/// Object shape for the drag model, picked on the CLI via `--shape`.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Shape {
+    Sphere,
+    Cube,
+}
+
+// dynamic viscosity of air, Pa*s
+const AIR_VISCOSITY: f64 = 1.8e-5;
+// reference density (kg/m^3) AIR_VISCOSITY is quoted at; Stokes drag is scaled
+// by density/AIR_DENSITY_SEA_LEVEL so it vanishes in near-vacuum instead of
+// reporting a spurious nonzero drag with no air to cause it
+const AIR_DENSITY_SEA_LEVEL: f64 = 1.225;
+
+fn reynolds_number(density: f64, velocity: f64, length: f64) -> f64 {
+    if density <= 0.0 || velocity == 0.0 {
+        0.0
+    } else {
+        density * velocity.abs() * length / AIR_VISCOSITY
+    }
+}
+
+// Clift/Schiller-Naumann sphere drag coefficient curve, valid across the Re
+// range from creeping flow up through the Newtonian regime.
+fn drag_coefficient_sphere(re: f64) -> f64 {
+    (24.0 / re) * (1.0 + 0.15 * re.powf(0.687)) + 0.42 / (1.0 + 42500.0 * re.powf(-1.16))
+}
+
+fn f_drag(density: f64, velocity: f64, width: f64, shape: Shape) -> f64 {
+    match shape {
+        // presume a cube with a fixed drag coefficient
+        Shape::Cube => 0.5 * density * velocity * velocity * width * width * 1.09,
+        Shape::Sphere => {
+            let radius = width / 2.0;
+            let re = reynolds_number(density, velocity, width);
+            if re < 1.0 {
+                f_stokes(AIR_VISCOSITY, radius, velocity) * (density / AIR_DENSITY_SEA_LEVEL)
+            } else {
+                let area = std::f64::consts::PI * radius * radius;
+                0.5 * density * velocity * velocity * area * drag_coefficient_sphere(re)
+            }
+        }
+    }
 }
 
-#[allow(dead_code)]
 fn f_stokes(viscosity: f64, radius: f64, velocity: f64) -> f64 {
     6.0 * std::f64::consts::PI * viscosity * radius * velocity
 }